@@ -1,5 +1,5 @@
-use tokio::io::AsyncBufReadExt;
-use tokio::io::AsyncWriteExt;
+use std::sync::Arc;
+
 use tokio::io::BufReader;
 use tokio::io::BufWriter;
 use tokio::sync::broadcast;
@@ -8,9 +8,11 @@ use tokio::sync::broadcast::error::RecvError;
 use tracing::info;
 
 use crate::commands::server::Message;
+use crate::socket::codec::Codec;
 use crate::socket::wrapper::OwnedWriteHalf;
 use crate::socket::wrapper::SocketAddr;
 use crate::socket::wrapper::Stream;
+use crate::transform::Pipeline;
 
 /// # Handle Incoming Messages
 ///
@@ -24,6 +26,9 @@ use crate::socket::wrapper::Stream;
 /// - `tx`: A broadcast channel sender for sending messages
 ///   (`broadcast::Sender<Message>`).
 /// - `addr`: The socket address of the connected client (`SocketAddr`).
+/// - `codec`: The framing codec used to decode and encode messages (`Codec`).
+/// - `pipeline`: The resolved transform and acknowledgment behavior
+///   (`Arc<Pipeline>`).
 ///
 /// ## Returns
 ///
@@ -34,41 +39,40 @@ use crate::socket::wrapper::Stream;
 ///
 /// ```rust
 /// // Example usage in an asynchronous context
-/// handle_message(stream, tx, addr).await?;
+/// handle_message(stream, tx, addr, codec, pipeline).await?;
 /// ```
 pub async fn handle_message(
     stream: Stream,
     tx: broadcast::Sender<Message>,
     addr: SocketAddr,
+    codec: Codec,
+    pipeline: Arc<Pipeline>,
 ) -> miette::Result<()> {
     let (reader, writer) = stream.into_split();
     let mut reader = BufReader::new(reader);
     let mut writer = BufWriter::new(writer);
 
-    let login_acknowledgment = &format!("LOGIN: {}\n", addr.port().unwrap());
-    writer.write_all(login_acknowledgment.as_bytes()).await.expect("write failed");
-    writer.flush().await.expect("flush failed");
+    let login_acknowledgment = format!("LOGIN: {}\n", addr.id());
+    codec.write_frame(&mut writer, login_acknowledgment.as_bytes()).await.expect("write failed");
 
     let mut rx = tx.subscribe();
-    let mut incoming = String::new();
     loop {
         let tx = tx.clone();
         tokio::select! {
             // Read from broadcast channel.
             result = rx.recv() => {
-                read_from_broadcast_channel(result, addr.clone(), &mut writer ).await?;
+                read_from_broadcast_channel(result, addr.clone(), &mut writer, &codec).await?;
             }
 
             // Read from socket.
-            network_read_result = reader.read_line(&mut incoming) => {
-                let num_bytes_read: usize = network_read_result.expect("read failed");
-                // EOF check.
-                if num_bytes_read == 0 {
-                    break;
+            frame = codec.read_frame(&mut reader) => {
+                match frame.expect("read failed") {
+                    // Clean EOF before a frame — the client disconnected.
+                    None => break,
+                    Some(payload) => {
+                        read_stream(&payload, &mut writer, tx, addr.clone(), &codec, &pipeline).await?;
+                    }
                 }
-
-                read_stream(num_bytes_read, &incoming, &mut writer, tx, addr.clone()).await?;
-                incoming.clear();
             }
         }
     }
@@ -88,6 +92,7 @@ pub async fn handle_message(
 /// - `addr`: The socket address of the connected client (`SocketAddr`).
 /// - `writer`: A mutable reference to a buffered writer for the network stream
 ///   (`&mut BufWriter<OwnedWriteHalf>`).
+/// - `codec`: The framing codec used to encode outgoing messages (`&Codec`).
 ///
 /// ## Returns
 ///
@@ -98,23 +103,23 @@ pub async fn handle_message(
 ///
 /// ```rust
 /// // Example usage in an asynchronous context
-/// read_from_broadcast_channel(result, addr, &mut writer).await?;
+/// read_from_broadcast_channel(result, addr, &mut writer, &codec).await?;
 /// ```
 async fn read_from_broadcast_channel(
     result: Result<Message, RecvError>,
     addr: SocketAddr,
     writer: &mut BufWriter<OwnedWriteHalf>,
+    codec: &Codec,
 ) -> miette::Result<()> {
     match result {
         Ok(it) => {
             let msg: Message = it;
-            if msg.addr.port().unwrap() != addr.port().unwrap() {
-                writer.write_all(msg.payload.as_bytes()).await.expect("write failed");
-                writer.flush().await.expect("flush failed");
+            if msg.addr.id() != addr.id() {
+                codec.write_frame(writer, msg.payload.as_bytes()).await.expect("write failed");
             }
         }
         Err(error) => {
-            info!("[{}]: channel error: {:?}", addr.port().unwrap(), error);
+            info!("[{}]: channel error: {:?}", addr.id(), error);
         }
     }
 
@@ -129,13 +134,14 @@ async fn read_from_broadcast_channel(
 ///
 /// ## Parameters
 ///
-/// - `num_bytes_read`: The number of bytes read from the network stream
-///   (`usize`).
-/// - `incoming`: The incoming message as a string slice (`&str`).
+/// - `payload`: The raw bytes of the decoded frame (`&[u8]`).
 /// - `writer`: A mutable reference to a buffered writer for the network stream
 ///   (`&mut BufWriter<OwnedWriteHalf>`).
 /// - `tx`: A broadcast channel sender for sending messages (`Sender<Message>`).
 /// - `addr`: The socket address of the connected client (`SocketAddr`).
+/// - `codec`: The framing codec used to encode the acknowledgment (`&Codec`).
+/// - `pipeline`: The resolved transform and acknowledgment behavior
+///   (`&Pipeline`).
 ///
 /// ## Returns
 ///
@@ -146,100 +152,45 @@ async fn read_from_broadcast_channel(
 ///
 /// ```rust
 /// // Example usage in an asynchronous context
-/// read_stream(num_bytes_read, incoming, &mut writer, tx, addr).await?;
+/// read_stream(payload, &mut writer, tx, addr, &codec, &pipeline).await?;
 /// ```
 async fn read_stream(
-    num_bytes_read: usize,
-    incoming: &str,
+    payload: &[u8],
     writer: &mut BufWriter<OwnedWriteHalf>,
     tx: Sender<Message>,
     addr: SocketAddr,
+    codec: &Codec,
+    pipeline: &Pipeline,
 ) -> miette::Result<()> {
+    let num_bytes_read = payload.len();
+    let incoming = String::from_utf8_lossy(payload);
+
     info!(
         "[{}]: incoming: {}, size: {}",
-        addr.port().unwrap(),
+        addr.id(),
         incoming.trim(),
         num_bytes_read
     );
 
-    let outgoing = handle_incoming_message(incoming);
+    let outgoing = pipeline.transform(&incoming);
 
     // Broadcast outgoing to the channel.
     let _ = tx.send(Message {
         addr: addr.clone(),
-        payload: handle_forward_message(addr.port().unwrap(), outgoing.to_string()),
-        from: addr.port().unwrap().to_string(),
+        payload: pipeline.forward(&addr.id(), &outgoing),
+        from: addr.id(),
     });
 
     info!(
         "[{}]: outgoing: {}, size: {}",
-        addr.port().unwrap(),
+        addr.id(),
         outgoing.trim(),
         num_bytes_read
     );
 
-    let acknowledgment = handle_acknowledgment_message();
-    writer.write_all(acknowledgment.as_bytes()).await.expect("write failed");
-    writer.flush().await.expect("flush failed");
+    if let Some(acknowledgment) = pipeline.acknowledgment() {
+        codec.write_frame(writer, acknowledgment.as_bytes()).await.expect("write failed");
+    }
 
     Ok(())
 }
-
-/// # Handle Incoming Message
-///
-/// Converts an incoming message to uppercase.
-///
-/// ## Parameters
-///
-/// - `incoming`: The incoming message as a string slice (`&str`).
-///
-/// ## Returns
-///
-/// A `String` representing the uppercase version of the incoming message.
-///
-/// ## Example
-///
-/// ```rust
-/// let result = handle_incoming_message("hello");
-/// assert_eq!(result, "HELLO");
-/// ```
-fn handle_incoming_message(incoming: &str) -> String { incoming.to_uppercase() }
-
-/// # Handle Acknowledgment Message
-///
-/// Generates a standard acknowledgment message.
-///
-/// ## Returns
-///
-/// A `String` representing the acknowledgment message.
-///
-/// ## Example
-///
-/// ```rust
-/// let acknowledgment = handle_acknowledgment_message();
-/// assert_eq!(acknowledgment, "ACK:MESSAGE\n");
-/// ```
-fn handle_acknowledgment_message() -> String { "ACK:MESSAGE\n".to_string() }
-
-/// # Handle Forward Message
-///
-/// Formats a message for forwarding, including the sender's port.
-///
-/// ## Parameters
-///
-/// - `port`: The port number of the sender (`u16`).
-/// - `outgoing`: The outgoing message (`String`).
-///
-/// ## Returns
-///
-/// A `String` formatted for forwarding, including the port and message.
-///
-/// ## Example
-///
-/// ```rust
-/// let forwarded = handle_forward_message(8080, "REQUEST".to_string());
-/// assert_eq!(forwarded, "MESSAGE:8080 REQUEST");
-/// ```
-fn handle_forward_message(port: u16, outgoing: String) -> String {
-    format!("MESSAGE:{} {}", port, outgoing)
-}
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use miette::IntoDiagnostic;
 use tokio::sync::broadcast;
 use tokio::task;
@@ -21,7 +23,11 @@ pub struct Message {
 
 pub async fn setup() -> miette::Result<()> {
     let config = Config::default();
-    let listener = Listener::bind(&config.listen).await.expect("bind failed");
+    let listener =
+        Listener::bind(&config.listen, config.tls.as_ref(), config.websocket, &config.socket)
+            .await
+            .expect("bind failed");
+    let pipeline = Arc::new(config.pipeline().expect("invalid transform configuration"));
     info!(socket = ?config.listen, "listening");
 
     // Create channel shared among all clients that connect to the server loop.
@@ -31,11 +37,13 @@ pub async fn setup() -> miette::Result<()> {
         match listener.accept().await {
             Ok((stream, addr)) => {
                 let tx = tx.clone();
+                let codec = config.codec.clone();
+                let pipeline = pipeline.clone();
                 tokio::spawn(async move {
                     info!(socket = ?addr, "client connected");
                     task::spawn(
                         async move {
-                            match client::handle_message(stream, tx, addr).await {
+                            match client::handle_message(stream, tx, addr, codec, pipeline).await {
                                 Ok(_) => {}
                                 Err(err) => error!("client error: {err:?}"),
                             }
@@ -1,17 +1,33 @@
+use std::fmt;
 use std::net::IpAddr;
+use std::net::SocketAddr as StdSocketAddr;
+use std::path::PathBuf;
+use std::str::FromStr;
 
-use serde_derive::Deserialize;
-use serde_derive::Serialize;
+use anyhow::Result;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+use serde_derive::Deserialize as DeriveDeserialize;
+use serde_derive::Serialize as DeriveSerialize;
+
+use crate::socket::codec::Codec;
+use crate::transform::Pipeline;
+use crate::transform::Transform;
 
 /// # Address Enum
 ///
-/// Represents a network address. Currently supports TCP addresses composed of
-/// an IP address and a port.
+/// Represents a network address the multiplexer can listen on. Supports TCP
+/// addresses composed of an IP address and a port, as well as Unix domain
+/// socket paths for local IPC without exposing a TCP port.
 ///
 /// ## Variants
 ///
 /// - `Tcp(IpAddr, u16)`: Represents a TCP address with an IP address and a port
 ///   number.
+/// - `Unix(PathBuf)`: Represents a Unix domain socket bound to a filesystem
+///   path.
 ///
 /// ## Example
 ///
@@ -20,10 +36,142 @@ use serde_derive::Serialize;
 /// use std::net::Ipv4Addr;
 /// let address = Address::Tcp(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 27_632);
 /// ```
-#[derive(Serialize, Deserialize, Debug)]
-#[serde(untagged)]
+#[derive(Debug, Clone)]
 pub enum Address {
     Tcp(IpAddr, u16),
+    Unix(PathBuf),
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Address::Tcp(ip, port) => write!(f, "{ip}:{port}"),
+            Address::Unix(path) => write!(f, "unix:{}", path.display()),
+        }
+    }
+}
+
+impl FromStr for Address {
+    type Err = String;
+
+    /// Parses either a `host:port` TCP address (e.g. `127.0.0.1:27632`) or a
+    /// `unix:/path/to/sock` Unix domain socket path.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(path) = s.strip_prefix("unix:") {
+            Ok(Address::Unix(PathBuf::from(path)))
+        } else {
+            let addr: StdSocketAddr =
+                s.parse().map_err(|err| format!("invalid tcp address `{s}`: {err}"))?;
+            Ok(Address::Tcp(addr.ip(), addr.port()))
+        }
+    }
+}
+
+impl Serialize for Address {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Address {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = String::deserialize(deserializer)?;
+        raw.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// # TLS Settings
+///
+/// Enables TLS termination on the listener. When present in [`Config`], each
+/// accepted TCP connection is wrapped in a `tokio_rustls` server stream using
+/// the configured certificate chain and private key. Supplying `client_ca`
+/// turns on mutual TLS, requiring connecting clients to present a certificate
+/// signed by that authority.
+///
+/// ## Fields
+///
+/// - `cert`: Path to the PEM-encoded certificate chain.
+/// - `key`: Path to the PEM-encoded private key.
+/// - `client_ca`: Optional path to a PEM-encoded CA bundle used to verify
+///   client certificates (mTLS).
+///
+/// ## Example
+///
+/// ```rust
+/// let tls = Tls {
+///     cert: "server.crt".into(),
+///     key: "server.key".into(),
+///     client_ca: None,
+/// };
+/// ```
+#[derive(DeriveSerialize, DeriveDeserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct Tls {
+    pub cert: PathBuf,
+    pub key: PathBuf,
+    #[serde(default)]
+    pub client_ca: Option<PathBuf>,
+}
+
+/// # Socket Tuning Options
+///
+/// Low-level TCP socket tuning applied when binding and accepting connections.
+/// The line-at-a-time broadcast pattern is sensitive to Nagle-induced latency,
+/// so these knobs let operators disable Nagle, tune keepalive and buffer sizes,
+/// and control the listen backlog. All defaults preserve the behavior of a
+/// plain `TcpListener::bind`.
+///
+/// ## Fields
+///
+/// - `reuse_addr`: Whether to set `SO_REUSEADDR` on the listening socket.
+/// - `backlog`: The listen backlog passed to `listen`.
+/// - `nodelay`: Whether to set `TCP_NODELAY` on each accepted stream.
+/// - `keepalive`: Optional TCP keepalive idle time, in seconds.
+/// - `send_buffer_size`: Optional `SO_SNDBUF` size, in bytes.
+/// - `recv_buffer_size`: Optional `SO_RCVBUF` size, in bytes.
+///
+/// ## Example
+///
+/// ```rust
+/// let socket = SocketOptions {
+///     nodelay: true,
+///     ..SocketOptions::default()
+/// };
+/// ```
+#[derive(DeriveSerialize, DeriveDeserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct SocketOptions {
+    #[serde(default = "default::reuse_addr")]
+    pub reuse_addr: bool,
+    #[serde(default = "default::backlog")]
+    pub backlog: u32,
+    #[serde(default)]
+    pub nodelay: bool,
+    #[serde(default)]
+    pub keepalive: Option<u64>,
+    #[serde(default)]
+    pub send_buffer_size: Option<u32>,
+    #[serde(default)]
+    pub recv_buffer_size: Option<u32>,
+}
+
+impl Default for SocketOptions {
+    fn default() -> Self {
+        SocketOptions {
+            reuse_addr: default::reuse_addr(),
+            backlog: default::backlog(),
+            nodelay: false,
+            keepalive: None,
+            send_buffer_size: None,
+            recv_buffer_size: None,
+        }
+    }
 }
 
 mod default {
@@ -43,9 +191,23 @@ mod default {
     /// ## Example
     ///
     /// ```rust
-    /// let default_address = default::listen(); 
+    /// let default_address = default::listen();
     /// ```
     pub fn listen() -> Address { Address::Tcp(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 27_632) }
+
+    /// Default acknowledgment toggle, preserving the historical always-ack
+    /// behavior.
+    pub fn ack() -> bool { true }
+
+    /// Default acknowledgment payload, matching the original `ACK:MESSAGE`
+    /// reply.
+    pub fn ack_template() -> String { "ACK:MESSAGE\n".to_string() }
+
+    /// Default `SO_REUSEADDR` setting, matching `TcpListener::bind`.
+    pub fn reuse_addr() -> bool { true }
+
+    /// Default listen backlog, matching `TcpListener::bind`.
+    pub fn backlog() -> u32 { 1024 }
 }
 
 // # Config Struct
@@ -57,6 +219,8 @@ mod default {
 ///
 /// - `listen`: An `Address` specifying where the application should listen for
 ///   incoming connections. Defaults to `127.0.0.1:27632` if not specified.
+/// - `codec`: The `Codec` used to frame messages on the wire. Defaults to the
+///   line-based framer to preserve existing behavior.
 ///
 /// ## Example
 ///
@@ -64,17 +228,64 @@ mod default {
 /// let config = Config::default();
 /// println!("{:?}", config.listen); // Outputs: Tcp(127.0.0.1:27632)
 /// ```
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(DeriveSerialize, DeriveDeserialize, Debug)]
 #[serde(deny_unknown_fields)]
 pub struct Config {
     #[serde(default = "default::listen")]
     pub listen: Address,
+
+    #[serde(default)]
+    pub codec: Codec,
+
+    #[serde(default)]
+    pub tls: Option<Tls>,
+
+    #[serde(default)]
+    pub transform: Transform,
+
+    #[serde(default = "default::ack")]
+    pub ack: bool,
+
+    #[serde(default = "default::ack_template")]
+    pub ack_template: String,
+
+    #[serde(default)]
+    pub socket: SocketOptions,
+
+    #[serde(default)]
+    pub websocket: bool,
 }
 
 impl Default for Config {
     fn default() -> Self {
         Config {
             listen: default::listen(),
+            codec: Codec::default(),
+            tls: None,
+            transform: Transform::default(),
+            ack: default::ack(),
+            ack_template: default::ack_template(),
+            socket: SocketOptions::default(),
+            websocket: false,
         }
     }
 }
+
+impl Config {
+    /// # Resolve the Message Pipeline
+    ///
+    /// Resolves the configured transform and acknowledgment settings into a
+    /// [`Pipeline`] once, compiling any regular expression up front so the
+    /// per-message path stays allocation-light.
+    ///
+    /// ## Returns
+    ///
+    /// A `Result<Pipeline>`, erroring if a `Regex` transform fails to compile.
+    pub fn pipeline(&self) -> Result<Pipeline> {
+        Ok(Pipeline::new(
+            self.transform.resolve()?,
+            self.ack,
+            self.ack_template.clone(),
+        ))
+    }
+}
@@ -0,0 +1,131 @@
+use std::io;
+
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+use tokio::io::AsyncBufRead;
+use tokio::io::AsyncBufReadExt;
+use tokio::io::AsyncRead;
+use tokio::io::AsyncReadExt;
+use tokio::io::AsyncWrite;
+use tokio::io::AsyncWriteExt;
+
+/// # Codec Enum
+///
+/// Selects how message payloads are framed on the wire. The line-based framer
+/// preserves the original newline-terminated behavior, while the
+/// length-delimited framer can carry arbitrary binary payloads — including data
+/// containing newlines — by prefixing each frame with its length.
+///
+/// ## Variants
+///
+/// - `Line`: Frames terminated by a single `\n`, as read by `read_line`.
+/// - `LengthDelimited { max_frame }`: Frames prefixed with a 4-byte big-endian
+///   `u32` length, rejecting any frame whose advertised length exceeds
+///   `max_frame`.
+///
+/// ## Example
+///
+/// ```rust
+/// let codec = Codec::LengthDelimited { max_frame: 1 << 20 };
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum Codec {
+    Line,
+    LengthDelimited { max_frame: usize },
+}
+
+impl Default for Codec {
+    fn default() -> Self { Codec::Line }
+}
+
+impl Codec {
+    /// # Read One Frame
+    ///
+    /// Reads a single message payload from `reader` according to the codec. A
+    /// clean EOF before any frame bytes arrive is reported as `None`, signalling
+    /// a normal disconnect.
+    ///
+    /// ## Parameters
+    ///
+    /// - `reader`: A buffered async reader to pull the frame from.
+    ///
+    /// ## Returns
+    ///
+    /// `Ok(Some(payload))` for a complete frame, `Ok(None)` on clean EOF, or an
+    /// error if the frame is malformed or exceeds `max_frame`.
+    pub async fn read_frame<R>(&self, reader: &mut R) -> io::Result<Option<Vec<u8>>>
+    where
+        R: AsyncBufRead + Unpin,
+    {
+        match self {
+            Codec::Line => {
+                let mut line = String::new();
+                let read = reader.read_line(&mut line).await?;
+                if read == 0 {
+                    return Ok(None);
+                }
+                Ok(Some(line.into_bytes()))
+            }
+            Codec::LengthDelimited { max_frame } => read_length_delimited(reader, *max_frame).await,
+        }
+    }
+
+    /// # Write One Frame
+    ///
+    /// Encodes `payload` onto `writer` according to the codec: line codecs emit
+    /// the raw bytes as-is, while the length-delimited codec writes a 4-byte
+    /// big-endian length prefix followed by the payload.
+    pub async fn write_frame<W>(&self, writer: &mut W, payload: &[u8]) -> io::Result<()>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        match self {
+            Codec::Line => {
+                writer.write_all(payload).await?;
+            }
+            Codec::LengthDelimited { .. } => {
+                let len = payload.len() as u32;
+                writer.write_all(&len.to_be_bytes()).await?;
+                writer.write_all(payload).await?;
+            }
+        }
+        writer.flush().await
+    }
+}
+
+/// Reads a single length-delimited frame, looping until the full payload has
+/// arrived and rejecting frames larger than `max_frame` before allocating.
+async fn read_length_delimited<R>(reader: &mut R, max_frame: usize) -> io::Result<Option<Vec<u8>>>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut len_bytes = [0u8; 4];
+    let mut filled = 0;
+    while filled < len_bytes.len() {
+        let read = reader.read(&mut len_bytes[filled..]).await?;
+        if read == 0 {
+            // A clean EOF before the first length byte is a normal disconnect.
+            if filled == 0 {
+                return Ok(None);
+            }
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "connection closed inside a frame length prefix",
+            ));
+        }
+        filled += read;
+    }
+
+    let len = u32::from_be_bytes(len_bytes) as usize;
+    if len > max_frame {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("frame length {len} exceeds max_frame {max_frame}"),
+        ));
+    }
+
+    let mut payload = vec![0u8; len];
+    reader.read_exact(&mut payload).await?;
+    Ok(Some(payload))
+}
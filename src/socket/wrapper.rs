@@ -1,21 +1,49 @@
 use std::fmt;
+use std::fs::File;
 use std::io;
+use std::io::BufReader as StdBufReader;
 use std::net;
+use std::path::Path;
+use std::path::PathBuf;
 use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::task::Context;
 use std::task::Poll;
+use std::time::Duration;
 
 use anyhow::Context as _;
 use anyhow::Result;
+use anyhow::anyhow;
 use pin_project_lite::pin_project;
+use rustls::RootCertStore;
+use rustls::ServerConfig;
+use rustls::pki_types::CertificateDer;
+use rustls::pki_types::PrivateKeyDer;
+use rustls::server::WebPkiClientVerifier;
+use socket2::SockRef;
+use socket2::TcpKeepalive;
 use tokio::io::AsyncRead;
 use tokio::io::AsyncWrite;
 use tokio::io::ReadBuf;
+use tokio::io::ReadHalf;
+use tokio::io::WriteHalf;
 use tokio::net::TcpListener;
+use tokio::net::TcpSocket;
 use tokio::net::TcpStream;
+use tokio::net::UnixListener;
+use tokio::net::UnixStream;
 use tokio::net::tcp;
+use tokio::net::unix;
+use tokio_rustls::TlsAcceptor;
+use tokio_rustls::server::TlsStream;
+use tokio_tungstenite::accept_async;
 
 use crate::config::Address;
+use crate::config::SocketOptions;
+use crate::config::Tls;
+use crate::socket::ws::WsStream;
 
 /// # Socket Address Enum
 ///
@@ -24,15 +52,19 @@ use crate::config::Address;
 /// ## Variants
 ///
 /// - `Ip(net::SocketAddr)`: Represents an IP socket address.
+/// - `Unix(PathBuf)`: Represents a Unix domain socket peer, identified by the
+///   bound socket path plus a per-connection accept sequence so each client is
+///   distinguishable.
 ///
 /// ## Example
 ///
 /// ```rust
-/// let addr = SocketAddr::Ip("127.0.0.1:8080".parse().unwrap()); 
+/// let addr = SocketAddr::Ip("127.0.0.1:8080".parse().unwrap());
 /// ```
 #[derive(Debug)]
 pub enum SocketAddr {
     Ip(net::SocketAddr),
+    Unix(PathBuf),
 }
 
 impl SocketAddr {
@@ -42,7 +74,8 @@ impl SocketAddr {
     ///
     /// ## Returns
     ///
-    /// An `Option<u16>` containing the port number or `None` if not applicable.
+    /// An `Option<u16>` containing the port number, or `None` for Unix domain
+    /// sockets which have no port.
     ///
     /// ## Example
     ///
@@ -53,6 +86,23 @@ impl SocketAddr {
     pub fn port(&self) -> Option<u16> {
         match self {
             SocketAddr::Ip(addr) => Some(addr.port()),
+            SocketAddr::Unix(_) => None,
+        }
+    }
+
+    // # Stable Client Identifier
+    ///
+    /// Returns a stable identifier for the peer, used to exclude a sender from
+    /// its own broadcasts. TCP peers are identified by their port; Unix peers,
+    /// which have no port, fall back to their accept-sequenced socket path.
+    ///
+    /// ## Returns
+    ///
+    /// A `String` uniquely identifying the connected client.
+    pub fn id(&self) -> String {
+        match self {
+            SocketAddr::Ip(addr) => addr.port().to_string(),
+            SocketAddr::Unix(path) => path.display().to_string(),
         }
     }
 }
@@ -61,6 +111,7 @@ impl Clone for SocketAddr {
     fn clone(&self) -> Self {
         match self {
             SocketAddr::Ip(addr) => SocketAddr::Ip(*addr),
+            SocketAddr::Unix(path) => SocketAddr::Unix(path.clone()),
         }
     }
 }
@@ -69,6 +120,7 @@ impl fmt::Display for SocketAddr {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             SocketAddr::Ip(addr) => write!(f, "{}:{}", addr.ip(), addr.port()),
+            SocketAddr::Unix(path) => write!(f, "unix:{}", path.display()),
         }
     }
 }
@@ -82,6 +134,9 @@ pin_project! {
     #[project = OwnedReadHalfProj]
     pub enum OwnedReadHalf {
         Tcp{#[pin] tcp: tcp::OwnedReadHalf},
+        Unix{#[pin] unix: unix::OwnedReadHalf},
+        Tls{#[pin] tls: ReadHalf<TlsStream<TcpStream>>},
+        Ws{#[pin] ws: ReadHalf<WsStream<TcpStream>>},
     }
 }
 
@@ -93,6 +148,9 @@ impl AsyncRead for OwnedReadHalf {
     ) -> Poll<io::Result<()>> {
         match self.project() {
             OwnedReadHalfProj::Tcp { tcp } => tcp.poll_read(cx, buf),
+            OwnedReadHalfProj::Unix { unix } => unix.poll_read(cx, buf),
+            OwnedReadHalfProj::Tls { tls } => tls.poll_read(cx, buf),
+            OwnedReadHalfProj::Ws { ws } => ws.poll_read(cx, buf),
         }
     }
 }
@@ -102,6 +160,9 @@ pin_project! {
     #[project = OwnedWriteHalfProj]
     pub enum OwnedWriteHalf {
         Tcp{#[pin] tcp: tcp::OwnedWriteHalf},
+        Unix{#[pin] unix: unix::OwnedWriteHalf},
+        Tls{#[pin] tls: WriteHalf<TlsStream<TcpStream>>},
+        Ws{#[pin] ws: WriteHalf<WsStream<TcpStream>>},
     }
 }
 
@@ -113,6 +174,9 @@ impl AsyncWrite for OwnedWriteHalf {
     ) -> Poll<Result<usize, io::Error>> {
         match self.project() {
             OwnedWriteHalfProj::Tcp { tcp } => tcp.poll_write(cx, buf),
+            OwnedWriteHalfProj::Unix { unix } => unix.poll_write(cx, buf),
+            OwnedWriteHalfProj::Tls { tls } => tls.poll_write(cx, buf),
+            OwnedWriteHalfProj::Ws { ws } => ws.poll_write(cx, buf),
         }
     }
 
@@ -123,18 +187,27 @@ impl AsyncWrite for OwnedWriteHalf {
     ) -> Poll<Result<usize, io::Error>> {
         match self.project() {
             OwnedWriteHalfProj::Tcp { tcp } => tcp.poll_write_vectored(cx, bufs),
+            OwnedWriteHalfProj::Unix { unix } => unix.poll_write_vectored(cx, bufs),
+            OwnedWriteHalfProj::Tls { tls } => tls.poll_write_vectored(cx, bufs),
+            OwnedWriteHalfProj::Ws { ws } => ws.poll_write_vectored(cx, bufs),
         }
     }
 
     fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), io::Error>> {
         match self.project() {
             OwnedWriteHalfProj::Tcp { tcp } => tcp.poll_flush(cx),
+            OwnedWriteHalfProj::Unix { unix } => unix.poll_flush(cx),
+            OwnedWriteHalfProj::Tls { tls } => tls.poll_flush(cx),
+            OwnedWriteHalfProj::Ws { ws } => ws.poll_flush(cx),
         }
     }
 
     fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), io::Error>> {
         match self.project() {
             OwnedWriteHalfProj::Tcp { tcp } => tcp.poll_shutdown(cx),
+            OwnedWriteHalfProj::Unix { unix } => unix.poll_shutdown(cx),
+            OwnedWriteHalfProj::Tls { tls } => tls.poll_shutdown(cx),
+            OwnedWriteHalfProj::Ws { ws } => ws.poll_shutdown(cx),
         }
     }
 }
@@ -144,6 +217,9 @@ pin_project! {
     #[project = StreamProj]
     pub enum Stream {
         Tcp{#[pin] tcp: TcpStream},
+        Unix{#[pin] unix: UnixStream},
+        Tls{#[pin] tls: TlsStream<TcpStream>},
+        Ws{#[pin] ws: WsStream<TcpStream>},
     }
 }
 
@@ -156,30 +232,202 @@ impl Stream {
                     tcp: write,
                 })
             }
+            Stream::Unix { unix } => {
+                let (read, write) = unix.into_split();
+                (OwnedReadHalf::Unix { unix: read }, OwnedWriteHalf::Unix {
+                    unix: write,
+                })
+            }
+            // A TLS stream can't be `into_split`, so fall back to the generic
+            // `tokio::io::split` reader/writer halves.
+            Stream::Tls { tls } => {
+                let (read, write) = tokio::io::split(tls);
+                (OwnedReadHalf::Tls { tls: read }, OwnedWriteHalf::Tls {
+                    tls: write,
+                })
+            }
+            // Likewise the WebSocket adapter is split with `tokio::io::split`.
+            Stream::Ws { ws } => {
+                let (read, write) = tokio::io::split(ws);
+                (OwnedReadHalf::Ws { ws: read }, OwnedWriteHalf::Ws {
+                    ws: write,
+                })
+            }
         }
     }
 }
 
 pub enum Listener {
-    Tcp(TcpListener),
+    Tcp {
+        listener: TcpListener,
+        tls: Option<TlsAcceptor>,
+        websocket: bool,
+        options: SocketOptions,
+    },
+    Unix {
+        listener: UnixListener,
+        path: PathBuf,
+        next_id: AtomicU64,
+    },
 }
 
 impl Listener {
-    pub async fn bind(addr: &Address) -> Result<Listener> {
+    pub async fn bind(
+        addr: &Address,
+        tls: Option<&Tls>,
+        websocket: bool,
+        options: &SocketOptions,
+    ) -> Result<Listener> {
         match addr {
-            Address::Tcp(ip_addr, port) => TcpListener::bind((*ip_addr, *port))
-                .await
-                .with_context(|| format!("binding to tcp socket {ip_addr}:{port}"))
-                .map(Listener::Tcp),
+            Address::Tcp(ip_addr, port) => {
+                let listener = bind_tcp((*ip_addr, *port).into(), options)
+                    .with_context(|| format!("binding to tcp socket {ip_addr}:{port}"))?;
+                let tls = tls.map(build_tls_acceptor).transpose()?;
+                Ok(Listener::Tcp {
+                    listener,
+                    tls,
+                    websocket,
+                    options: options.clone(),
+                })
+            }
+            Address::Unix(path) => UnixListener::bind(path)
+                .with_context(|| format!("binding to unix socket {}", path.display()))
+                .map(|listener| Listener::Unix {
+                    listener,
+                    path: path.clone(),
+                    next_id: AtomicU64::new(0),
+                }),
         }
     }
 
     pub async fn accept(&self) -> io::Result<(Stream, SocketAddr)> {
         match self {
-            Listener::Tcp(tcp) => {
-                let (stream, addr) = tcp.accept().await?;
-                Ok((Stream::Tcp { tcp: stream }, addr.into()))
+            Listener::Tcp {
+                listener,
+                tls,
+                websocket,
+                options,
+            } => {
+                let (stream, addr) = listener.accept().await?;
+                apply_stream_options(&stream, options)?;
+                match tls {
+                    Some(acceptor) => {
+                        let stream = acceptor.accept(stream).await?;
+                        Ok((Stream::Tls { tls: stream }, addr.into()))
+                    }
+                    None if *websocket => {
+                        // Perform the HTTP upgrade and expose the socket uniformly.
+                        let ws = accept_async(stream)
+                            .await
+                            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+                        Ok((Stream::Ws { ws: WsStream::new(ws) }, addr.into()))
+                    }
+                    None => Ok((Stream::Tcp { tcp: stream }, addr.into())),
+                }
+            }
+            Listener::Unix {
+                listener,
+                path,
+                next_id,
+            } => {
+                let (stream, _addr) = listener.accept().await?;
+                // Unix peers are usually unnamed, so tag each accepted
+                // connection with a sequence id to keep clients distinguishable.
+                let id = next_id.fetch_add(1, Ordering::Relaxed);
+                let mut conn_path = path.clone().into_os_string();
+                conn_path.push(format!("#{id}"));
+                Ok((Stream::Unix { unix: stream }, SocketAddr::Unix(conn_path.into())))
+            }
+        }
+    }
+}
+
+/// Builds a `TlsAcceptor` from the configured certificate chain, private key,
+/// and optional client-CA bundle. When a client CA is supplied the acceptor is
+/// configured for mutual TLS, rejecting clients without a trusted certificate.
+fn build_tls_acceptor(tls: &Tls) -> Result<TlsAcceptor> {
+    let certs = load_certs(&tls.cert)?;
+    let key = load_key(&tls.key)?;
+
+    let builder = ServerConfig::builder();
+    let builder = match &tls.client_ca {
+        Some(ca) => {
+            let mut roots = RootCertStore::empty();
+            for cert in load_certs(ca)? {
+                roots.add(cert).context("adding client CA certificate")?;
             }
+            let verifier = WebPkiClientVerifier::builder(Arc::new(roots))
+                .build()
+                .context("building client certificate verifier")?;
+            builder.with_client_cert_verifier(verifier)
         }
+        None => builder.with_no_client_auth(),
+    };
+
+    let config = builder
+        .with_single_cert(certs, key)
+        .context("configuring server certificate")?;
+
+    Ok(TlsAcceptor::from(Arc::new(config)))
+}
+
+/// Reads a PEM-encoded certificate chain from `path`.
+fn load_certs(path: &Path) -> Result<Vec<CertificateDer<'static>>> {
+    let file = File::open(path).with_context(|| format!("opening {}", path.display()))?;
+    rustls_pemfile::certs(&mut StdBufReader::new(file))
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .with_context(|| format!("reading certificates from {}", path.display()))
+}
+
+/// Reads a single PEM-encoded private key from `path`.
+fn load_key(path: &Path) -> Result<PrivateKeyDer<'static>> {
+    let file = File::open(path).with_context(|| format!("opening {}", path.display()))?;
+    rustls_pemfile::private_key(&mut StdBufReader::new(file))
+        .with_context(|| format!("reading private key from {}", path.display()))?
+        .ok_or_else(|| anyhow!("no private key found in {}", path.display()))
+}
+
+/// Builds a TCP listener via `TcpSocket` so socket options can be applied
+/// before binding: `SO_REUSEADDR`, send/recv buffer sizes, and the listen
+/// backlog. With default [`SocketOptions`] this matches `TcpListener::bind`.
+fn bind_tcp(addr: net::SocketAddr, options: &SocketOptions) -> io::Result<TcpListener> {
+    let socket = if addr.is_ipv4() {
+        TcpSocket::new_v4()?
+    } else {
+        TcpSocket::new_v6()?
+    };
+
+    socket.set_reuseaddr(options.reuse_addr)?;
+    if let Some(size) = options.send_buffer_size {
+        socket.set_send_buffer_size(size)?;
     }
+    if let Some(size) = options.recv_buffer_size {
+        socket.set_recv_buffer_size(size)?;
+    }
+
+    socket.bind(addr)?;
+    socket.listen(options.backlog)
+}
+
+/// Applies per-connection socket options to an accepted stream: `TCP_NODELAY`,
+/// keepalive, and send/recv buffer sizes. Options left unset preserve the
+/// kernel defaults.
+fn apply_stream_options(stream: &TcpStream, options: &SocketOptions) -> io::Result<()> {
+    if options.nodelay {
+        stream.set_nodelay(true)?;
+    }
+
+    let socket = SockRef::from(stream);
+    if let Some(secs) = options.keepalive {
+        let keepalive = TcpKeepalive::new().with_time(Duration::from_secs(secs));
+        socket.set_tcp_keepalive(&keepalive)?;
+    }
+    if let Some(size) = options.send_buffer_size {
+        socket.set_send_buffer_size(size as usize)?;
+    }
+    if let Some(size) = options.recv_buffer_size {
+        socket.set_recv_buffer_size(size as usize)?;
+    }
+
+    Ok(())
 }
@@ -0,0 +1,113 @@
+use std::io;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use std::task::ready;
+
+use futures_util::SinkExt;
+use futures_util::StreamExt;
+use tokio::io::AsyncRead;
+use tokio::io::AsyncWrite;
+use tokio::io::ReadBuf;
+use tokio_tungstenite::WebSocketStream;
+use tokio_tungstenite::tungstenite::Error as WsError;
+use tokio_tungstenite::tungstenite::Message as WsMessage;
+
+/// # WebSocket Stream Adapter
+///
+/// Adapts a message-oriented [`WebSocketStream`] into the byte-oriented
+/// `AsyncRead`/`AsyncWrite` interface the rest of the crate speaks, so browser
+/// clients can share the same broadcast channel as raw TCP peers. Each
+/// text/binary WebSocket frame is surfaced as one contiguous read, and each
+/// write is emitted as a single binary frame, keeping the one-frame-per-message
+/// correspondence the broadcast relay expects. Control frames (ping/pong) are
+/// handled transparently and a close frame reads as a clean EOF.
+pub struct WsStream<S> {
+    inner: WebSocketStream<S>,
+    read_buf: Vec<u8>,
+    read_pos: usize,
+}
+
+impl<S> WsStream<S> {
+    /// Wraps an already-upgraded WebSocket stream.
+    pub fn new(inner: WebSocketStream<S>) -> Self {
+        WsStream {
+            inner,
+            read_buf: Vec::new(),
+            read_pos: 0,
+        }
+    }
+}
+
+/// Maps a tungstenite error into the `io::Error` the IO traits require.
+fn ws_io_error(err: WsError) -> io::Error {
+    match err {
+        WsError::Io(err) => err,
+        other => io::Error::new(io::ErrorKind::Other, other),
+    }
+}
+
+impl<S> AsyncRead for WsStream<S>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+
+        loop {
+            // Drain any bytes left over from the previous frame first.
+            if this.read_pos < this.read_buf.len() {
+                let remaining = &this.read_buf[this.read_pos..];
+                let take = remaining.len().min(buf.remaining());
+                buf.put_slice(&remaining[..take]);
+                this.read_pos += take;
+                return Poll::Ready(Ok(()));
+            }
+
+            match ready!(this.inner.poll_next_unpin(cx)) {
+                Some(Ok(message)) => match message {
+                    WsMessage::Text(_) | WsMessage::Binary(_) => {
+                        this.read_buf = message.into_data().to_vec();
+                        this.read_pos = 0;
+                    }
+                    // Control frames carry no payload for the relay; keep polling.
+                    WsMessage::Ping(_) | WsMessage::Pong(_) | WsMessage::Frame(_) => continue,
+                    WsMessage::Close(_) => return Poll::Ready(Ok(())),
+                },
+                Some(Err(err)) => return Poll::Ready(Err(ws_io_error(err))),
+                // Stream exhausted — a clean disconnect.
+                None => return Poll::Ready(Ok(())),
+            }
+        }
+    }
+}
+
+impl<S> AsyncWrite for WsStream<S>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        ready!(this.inner.poll_ready_unpin(cx)).map_err(ws_io_error)?;
+        this.inner
+            .start_send_unpin(WsMessage::binary(buf.to_vec()))
+            .map_err(ws_io_error)?;
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.get_mut().inner.poll_flush_unpin(cx).map_err(ws_io_error)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.get_mut().inner.poll_close_unpin(cx).map_err(ws_io_error)
+    }
+}
@@ -0,0 +1,128 @@
+use anyhow::Context as _;
+use anyhow::Result;
+use regex::Regex;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+
+/// # Transform Enum
+///
+/// Selects how incoming payloads are rewritten before they are broadcast to the
+/// other clients. This replaces the previously hardcoded uppercasing so that
+/// operators decide the relay's behavior from configuration.
+///
+/// ## Variants
+///
+/// - `None`: Forward the payload untouched.
+/// - `Uppercase`: Uppercase the payload (the historical default).
+/// - `Lowercase`: Lowercase the payload.
+/// - `Prefix { text }`: Prepend a fixed string to the payload.
+/// - `Regex { pattern, replacement }`: Apply a regular-expression substitution.
+///
+/// ## Example
+///
+/// ```rust
+/// let transform = Transform::Prefix { text: "> ".into() };
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum Transform {
+    None,
+    Uppercase,
+    Lowercase,
+    Prefix { text: String },
+    Regex { pattern: String, replacement: String },
+}
+
+impl Default for Transform {
+    fn default() -> Self { Transform::Uppercase }
+}
+
+impl Transform {
+    /// Resolves the transform into its runtime form, compiling any regular
+    /// expression once so the hot path does not recompile per message.
+    pub fn resolve(&self) -> Result<ResolvedTransform> {
+        Ok(match self {
+            Transform::None => ResolvedTransform::None,
+            Transform::Uppercase => ResolvedTransform::Uppercase,
+            Transform::Lowercase => ResolvedTransform::Lowercase,
+            Transform::Prefix { text } => ResolvedTransform::Prefix(text.clone()),
+            Transform::Regex {
+                pattern,
+                replacement,
+            } => {
+                let regex =
+                    Regex::new(pattern).with_context(|| format!("compiling regex `{pattern}`"))?;
+                ResolvedTransform::Regex(regex, replacement.clone())
+            }
+        })
+    }
+}
+
+/// # Resolved Transform
+///
+/// The runtime counterpart of [`Transform`], with any regular expression
+/// already compiled. Built once at startup and applied to every payload.
+#[derive(Debug, Clone)]
+pub enum ResolvedTransform {
+    None,
+    Uppercase,
+    Lowercase,
+    Prefix(String),
+    Regex(Regex, String),
+}
+
+impl ResolvedTransform {
+    /// Applies the transform to a single payload.
+    pub fn apply(&self, input: &str) -> String {
+        match self {
+            ResolvedTransform::None => input.to_string(),
+            ResolvedTransform::Uppercase => input.to_uppercase(),
+            ResolvedTransform::Lowercase => input.to_lowercase(),
+            ResolvedTransform::Prefix(text) => format!("{text}{input}"),
+            ResolvedTransform::Regex(regex, replacement) => {
+                regex.replace_all(input, replacement.as_str()).into_owned()
+            }
+        }
+    }
+}
+
+/// # Pipeline
+///
+/// The resolved relay behavior threaded into the client loop: how payloads are
+/// rewritten, how they are labelled for fan-out, and whether each message is
+/// acknowledged.
+///
+/// ## Fields
+///
+/// - `transform`: The payload rewrite applied before broadcast.
+/// - `send_ack`: Whether to reply with an acknowledgment per message.
+/// - `ack_template`: The acknowledgment payload to send when `send_ack` is set.
+#[derive(Debug, Clone)]
+pub struct Pipeline {
+    transform: ResolvedTransform,
+    send_ack: bool,
+    ack_template: String,
+}
+
+impl Pipeline {
+    /// Builds a pipeline from its resolved parts.
+    pub fn new(transform: ResolvedTransform, send_ack: bool, ack_template: String) -> Self {
+        Pipeline {
+            transform,
+            send_ack,
+            ack_template,
+        }
+    }
+
+    /// Rewrites an incoming payload according to the configured transform.
+    pub fn transform(&self, incoming: &str) -> String { self.transform.apply(incoming) }
+
+    /// Formats a transformed payload for fan-out, tagging it with the sender id.
+    pub fn forward(&self, id: &str, outgoing: &str) -> String { format!("MESSAGE:{id} {outgoing}") }
+
+    /// Returns the acknowledgment payload to send back, or `None` when
+    /// acknowledgments are disabled.
+    pub fn acknowledgment(&self) -> Option<&str> {
+        self.send_ack.then_some(self.ack_template.as_str())
+    }
+}